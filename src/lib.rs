@@ -45,8 +45,13 @@
 //!
 //! There's also other methods besides [continue][BoolFlow::continue] and [break][BoolFlow::break] which allows to control the value which is passed to the `Break` variant.
 //!
+//! The crate only depends on `core`, so it works in `no_std` environments too. The default
+//! `std` feature is enabled for a smooth transition; disable it with `default-features = false`
+//! to drop the `std` dependency entirely.
 
-use std::ops::ControlFlow;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::ops::ControlFlow;
 
 use private::Sealed;
 
@@ -73,10 +78,80 @@ pub trait BoolFlow: Sealed {
 
     /// If `self` is `true`, it returns `ControlFlow::Continue`. Lazily returns `ControlFlow::Break(T)` otherwise.
     fn continue_or_else<T>(self, f: impl FnOnce() -> T) -> ControlFlow<T>;
+
+    /// Returns `ControlFlow::Break(())` if `self` is true, `ControlFlow::Continue(C)` otherwise.
+    fn break_or<C>(self, cont: C) -> ControlFlow<(), C>;
+
+    /// Returns `ControlFlow::Break(())` if `self` is true, lazily returns `ControlFlow::Continue(C)` otherwise.
+    fn break_or_else<C>(self, cont: impl FnOnce() -> C) -> ControlFlow<(), C>;
+
+    /// Returns `ControlFlow::Break(B)` if `self` is true, `ControlFlow::Continue(C)` otherwise.
+    fn break_with_or<B, C>(self, brk: B, cont: C) -> ControlFlow<B, C>;
+
+    /// Lazily returns `ControlFlow::Break(B)` if `self` is true, lazily returns `ControlFlow::Continue(C)` otherwise.
+    fn break_with_or_else<B, C>(
+        self,
+        brk: impl FnOnce() -> B,
+        cont: impl FnOnce() -> C,
+    ) -> ControlFlow<B, C>;
 }
 
 impl Sealed for bool {}
 
+/// Allows to convert from an `Option` to a `ControlFlow` in order to easily use the `?` operator.
+pub trait OptionFlow<T>: Sealed {
+    /// Returns `ControlFlow::Break(T)` if `self` is `Some`, `ControlFlow::Continue(())` otherwise.
+    fn break_on_some(self) -> ControlFlow<T>;
+
+    /// Returns `ControlFlow::Break(B)` if `self` is `None`, `ControlFlow::Continue(T)` otherwise.
+    fn break_on_none<B>(self, value: B) -> ControlFlow<B, T>;
+}
+
+impl<T> Sealed for Option<T> {}
+
+impl<T> OptionFlow<T> for Option<T> {
+    fn break_on_some(self) -> ControlFlow<T> {
+        match self {
+            Some(value) => ControlFlow::Break(value),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    fn break_on_none<B>(self, value: B) -> ControlFlow<B, T> {
+        match self {
+            Some(inner) => ControlFlow::Continue(inner),
+            None => ControlFlow::Break(value),
+        }
+    }
+}
+
+/// Allows to convert from a `Result` to a `ControlFlow` in order to easily use the `?` operator.
+pub trait ResultFlow<T, E>: Sealed {
+    /// Returns `ControlFlow::Break(T)` if `self` is `Ok`, `ControlFlow::Continue(())` otherwise.
+    fn break_on_ok(self) -> ControlFlow<T>;
+
+    /// Returns `ControlFlow::Break(B)` if `self` is `Err`, `ControlFlow::Continue(T)` otherwise.
+    fn break_on_err<B>(self, value: B) -> ControlFlow<B, T>;
+}
+
+impl<T, E> Sealed for Result<T, E> {}
+
+impl<T, E> ResultFlow<T, E> for Result<T, E> {
+    fn break_on_ok(self) -> ControlFlow<T> {
+        match self {
+            Ok(value) => ControlFlow::Break(value),
+            Err(_) => ControlFlow::Continue(()),
+        }
+    }
+
+    fn break_on_err<B>(self, value: B) -> ControlFlow<B, T> {
+        match self {
+            Ok(inner) => ControlFlow::Continue(inner),
+            Err(_) => ControlFlow::Break(value),
+        }
+    }
+}
+
 impl BoolFlow for bool {
     fn r#break(self) -> ControlFlow<()> {
         match self {
@@ -119,11 +194,136 @@ impl BoolFlow for bool {
             false => ControlFlow::Break(f()),
         }
     }
+
+    fn break_or<C>(self, cont: C) -> ControlFlow<(), C> {
+        match self {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(cont),
+        }
+    }
+
+    fn break_or_else<C>(self, cont: impl FnOnce() -> C) -> ControlFlow<(), C> {
+        match self {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(cont()),
+        }
+    }
+
+    fn break_with_or<B, C>(self, brk: B, cont: C) -> ControlFlow<B, C> {
+        match self {
+            true => ControlFlow::Break(brk),
+            false => ControlFlow::Continue(cont),
+        }
+    }
+
+    fn break_with_or_else<B, C>(
+        self,
+        brk: impl FnOnce() -> B,
+        cont: impl FnOnce() -> C,
+    ) -> ControlFlow<B, C> {
+        match self {
+            true => ControlFlow::Break(brk()),
+            false => ControlFlow::Continue(cont()),
+        }
+    }
+}
+
+impl<B, C> Sealed for ControlFlow<B, C> {}
+
+/// Adds conversion and inspection combinators to `ControlFlow` that std doesn't provide yet.
+pub trait ControlFlowExt<B, C>: Sealed {
+    /// Returns the `Break` value, or `None` if `self` is `Continue`.
+    fn break_value(self) -> Option<B>;
+
+    /// Returns the `Continue` value, or `None` if `self` is `Break`.
+    fn continue_value(self) -> Option<C>;
+
+    /// Maps the `Break` value, leaving a `Continue` value untouched.
+    fn map_break<B2>(self, f: impl FnOnce(B) -> B2) -> ControlFlow<B2, C>;
+
+    /// Maps the `Continue` value, leaving a `Break` value untouched.
+    fn map_continue<C2>(self, f: impl FnOnce(C) -> C2) -> ControlFlow<B, C2>;
+
+    /// Returns `true` if `self` is `Break`, `false` otherwise, matching the glib convention
+    /// where `Continue`/`Break` map cleanly to booleans. Round-trips with
+    /// [`from_break_bool`][Self::from_break_bool].
+    fn into_break_bool(self) -> bool;
+
+    /// Builds a `ControlFlow` from a `bool`, the reverse of
+    /// [`into_break_bool`][Self::into_break_bool]: `true` becomes `Break`, `false` becomes
+    /// `Continue`, matching the glib convention where `Continue`/`Break` map cleanly to booleans.
+    fn from_break_bool(value: bool) -> ControlFlow<B, C>
+    where
+        B: Default,
+        C: Default,
+    {
+        match value {
+            true => ControlFlow::Break(B::default()),
+            false => ControlFlow::Continue(C::default()),
+        }
+    }
+}
+
+impl<B, C> ControlFlowExt<B, C> for ControlFlow<B, C> {
+    fn break_value(self) -> Option<B> {
+        match self {
+            ControlFlow::Break(value) => Some(value),
+            ControlFlow::Continue(_) => None,
+        }
+    }
+
+    fn continue_value(self) -> Option<C> {
+        match self {
+            ControlFlow::Continue(value) => Some(value),
+            ControlFlow::Break(_) => None,
+        }
+    }
+
+    fn map_break<B2>(self, f: impl FnOnce(B) -> B2) -> ControlFlow<B2, C> {
+        match self {
+            ControlFlow::Break(value) => ControlFlow::Break(f(value)),
+            ControlFlow::Continue(value) => ControlFlow::Continue(value),
+        }
+    }
+
+    fn map_continue<C2>(self, f: impl FnOnce(C) -> C2) -> ControlFlow<B, C2> {
+        match self {
+            ControlFlow::Break(value) => ControlFlow::Break(value),
+            ControlFlow::Continue(value) => ControlFlow::Continue(f(value)),
+        }
+    }
+
+    fn into_break_bool(self) -> bool {
+        matches!(self, ControlFlow::Break(_))
+    }
+}
+
+/// Adds a traversal primitive that drives early exit from a plain `bool` predicate.
+pub trait FlowIteratorExt: Iterator {
+    /// Calls `predicate` on each item, stopping as soon as it returns `true`.
+    ///
+    /// Returns `ControlFlow::Break(item)` with the item that triggered the break, or
+    /// `ControlFlow::Continue(())` if the iterator is exhausted first.
+    fn try_for_each_while<F>(&mut self, predicate: F) -> ControlFlow<Self::Item>
+    where
+        F: FnMut(&Self::Item) -> bool;
+}
+
+impl<I: Iterator> FlowIteratorExt for I {
+    fn try_for_each_while<F>(&mut self, mut predicate: F) -> ControlFlow<Self::Item>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.try_fold((), |(), item| match predicate(&item).r#break() {
+            ControlFlow::Break(()) => ControlFlow::Break(item),
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::ops::ControlFlow;
+    use core::ops::ControlFlow;
 
     use crate::BoolFlow;
 
@@ -173,7 +373,7 @@ mod tests {
 
         {
             let mut continued = false;
-            should_continue(true, &mut continued);
+            let _ = should_continue(true, &mut continued);
             assert!(continued);
         }
         assert_eq!(test_continue_or(true), ControlFlow::Break(false));
@@ -181,10 +381,147 @@ mod tests {
 
         {
             let mut continued = false;
-            should_continue(false, &mut continued);
+            let _ = should_continue(false, &mut continued);
             assert!(!continued);
         }
         assert_eq!(test_continue_or(false), ControlFlow::Break(true));
         assert_eq!(test_continue_or_else(false), ControlFlow::Break(true));
     }
+
+    #[test]
+    fn break_or_works() {
+        fn accumulate(values: &[i32]) -> ControlFlow<(), i32> {
+            values.iter().try_fold(0, |acc, &value| {
+                (value < 0).break_or(acc + value)
+            })
+        }
+
+        fn accumulate_lazy(values: &[i32]) -> ControlFlow<(), i32> {
+            values.iter().try_fold(0, |acc, &value| {
+                (value < 0).break_or_else(|| acc + value)
+            })
+        }
+
+        assert_eq!(accumulate(&[1, 2, 3]), ControlFlow::Continue(6));
+        assert_eq!(accumulate(&[1, -2, 3]), ControlFlow::Break(()));
+
+        assert_eq!(accumulate_lazy(&[1, 2, 3]), ControlFlow::Continue(6));
+        assert_eq!(accumulate_lazy(&[1, -2, 3]), ControlFlow::Break(()));
+    }
+
+    #[test]
+    fn break_with_or_works() {
+        fn accumulate(values: &[i32]) -> ControlFlow<&'static str, i32> {
+            values.iter().try_fold(0, |acc, &value| {
+                (value < 0).break_with_or("negative value", acc + value)
+            })
+        }
+
+        fn accumulate_lazy(values: &[i32]) -> ControlFlow<&'static str, i32> {
+            values.iter().try_fold(0, |acc, &value| {
+                (value < 0).break_with_or_else(|| "negative value", || acc + value)
+            })
+        }
+
+        assert_eq!(accumulate(&[1, 2, 3]), ControlFlow::Continue(6));
+        assert_eq!(accumulate(&[1, -2, 3]), ControlFlow::Break("negative value"));
+
+        assert_eq!(accumulate_lazy(&[1, 2, 3]), ControlFlow::Continue(6));
+        assert_eq!(
+            accumulate_lazy(&[1, -2, 3]),
+            ControlFlow::Break("negative value")
+        );
+    }
+
+    #[test]
+    fn control_flow_ext_works() {
+        use crate::ControlFlowExt;
+
+        let broken: ControlFlow<i32, bool> = ControlFlow::Break(1);
+        let continued: ControlFlow<i32, bool> = ControlFlow::Continue(true);
+
+        assert_eq!(broken.break_value(), Some(1));
+        assert_eq!(continued.break_value(), None);
+
+        assert_eq!(broken.continue_value(), None);
+        assert_eq!(continued.continue_value(), Some(true));
+
+        assert_eq!(broken.map_break(|value| value + 1), ControlFlow::Break(2));
+        assert_eq!(
+            continued.map_break(|value| value + 1),
+            ControlFlow::Continue(true)
+        );
+
+        assert_eq!(broken.map_continue(|value| !value), ControlFlow::Break(1));
+        assert_eq!(
+            continued.map_continue(|value| !value),
+            ControlFlow::Continue(false)
+        );
+
+        assert!(broken.into_break_bool());
+        assert!(!continued.into_break_bool());
+
+        assert!(true.r#break().into_break_bool());
+        assert!(!false.r#break().into_break_bool());
+
+        let from_true = <ControlFlow<(), ()> as ControlFlowExt<(), ()>>::from_break_bool(true);
+        let from_false = <ControlFlow<(), ()> as ControlFlowExt<(), ()>>::from_break_bool(false);
+        assert_eq!(from_true, ControlFlow::Break(()));
+        assert_eq!(from_false, ControlFlow::Continue(()));
+        assert!(from_true.into_break_bool());
+        assert!(!from_false.into_break_bool());
+    }
+
+    #[test]
+    fn try_for_each_while_works() {
+        use crate::FlowIteratorExt;
+
+        let found = (2..500).try_for_each_while(|&x| 403 % x == 0);
+        assert_eq!(found, ControlFlow::Break(13));
+
+        let exhausted = (2..10).try_for_each_while(|&x| 403 % x == 0);
+        assert_eq!(exhausted, ControlFlow::Continue(()));
+    }
+
+    #[test]
+    fn option_flow_works() {
+        use crate::OptionFlow;
+
+        fn break_on_some(value: Option<i32>) -> ControlFlow<i32> {
+            value.break_on_some()?;
+            ControlFlow::Continue(())
+        }
+
+        fn break_on_none(value: Option<i32>) -> ControlFlow<bool, i32> {
+            let inner = value.break_on_none(true)?;
+            ControlFlow::Continue(inner)
+        }
+
+        assert_eq!(break_on_some(Some(1)), ControlFlow::Break(1));
+        assert_eq!(break_on_some(None), ControlFlow::Continue(()));
+
+        assert_eq!(break_on_none(Some(1)), ControlFlow::Continue(1));
+        assert_eq!(break_on_none(None), ControlFlow::Break(true));
+    }
+
+    #[test]
+    fn result_flow_works() {
+        use crate::ResultFlow;
+
+        fn break_on_ok(value: Result<i32, &str>) -> ControlFlow<i32> {
+            value.break_on_ok()?;
+            ControlFlow::Continue(())
+        }
+
+        fn break_on_err(value: Result<i32, &str>) -> ControlFlow<bool, i32> {
+            let inner = value.break_on_err(true)?;
+            ControlFlow::Continue(inner)
+        }
+
+        assert_eq!(break_on_ok(Ok(1)), ControlFlow::Break(1));
+        assert_eq!(break_on_ok(Err("oops")), ControlFlow::Continue(()));
+
+        assert_eq!(break_on_err(Ok(1)), ControlFlow::Continue(1));
+        assert_eq!(break_on_err(Err("oops")), ControlFlow::Break(true));
+    }
 }